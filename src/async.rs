@@ -0,0 +1,141 @@
+use alloc::collections::VecDeque;
+use core::{
+    fmt,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+
+/// [Transformed](crate::Transformed)の非同期版です。
+///
+/// 入力の`futures::Stream<Item = T>`と変換関数`FnMut(T) -> Result<U, E>`を使用して
+/// `Result<U, E>`を返す出力`futures::Stream`の実装です。
+pub struct AsyncTransformed<S, T, U, E, F>
+where
+    S: Stream<Item = T>,
+    F: FnMut(T) -> Result<U, E>,
+{
+    inner: S,
+    transform: F,
+    backing_store: VecDeque<U>,
+    phantom: PhantomData<Result<U, E>>,
+}
+
+impl<S, T, U, E, F> AsyncTransformed<S, T, U, E, F>
+where
+    S: Stream<Item = T>,
+    F: FnMut(T) -> Result<U, E>,
+{
+    pub fn new(inner: S, transform: F) -> Self {
+        Self {
+            inner,
+            transform,
+            backing_store: Default::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn with_backing_store(inner: S, transform: F, backing_store: impl IntoIterator<Item = U>) -> Self {
+        Self {
+            inner,
+            transform,
+            backing_store: VecDeque::from_iter(backing_store),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, T, U, E, F> fmt::Debug for AsyncTransformed<S, T, U, E, F>
+where
+    S: Stream<Item = T>,
+    F: FnMut(T) -> Result<U, E>,
+    U: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncTransformed")
+            .field("backing_store", &self.backing_store)
+            .field("phantom", &self.phantom)
+            .finish()
+    }
+}
+
+/// [UnRead](crate::UnRead)の非同期ストリーム向けの対応物です。
+///
+/// `poll_next`はプッシュバックされたトークンを内部ストリームより先に返します。
+pub trait AsyncUnRead<U> {
+    /// 一つのトークンをプッシュバックします。
+    fn unread(&mut self, token: U);
+
+    /// 複数のトークンをプッシュバックします。
+    fn unread_from_tokens(&mut self, iter: impl IntoIterator<Item = U>);
+}
+
+impl<S, T, U, E, F> AsyncUnRead<U> for AsyncTransformed<S, T, U, E, F>
+where
+    S: Stream<Item = T>,
+    F: FnMut(T) -> Result<U, E>,
+{
+    fn unread(&mut self, token: U) {
+        self.backing_store.push_back(token);
+    }
+
+    fn unread_from_tokens(&mut self, iter: impl IntoIterator<Item = U>) {
+        self.backing_store.extend(iter)
+    }
+}
+
+impl<S, T, U, E, F> Stream for AsyncTransformed<S, T, U, E, F>
+where
+    S: Stream<Item = T>,
+    F: FnMut(T) -> Result<U, E>,
+{
+    type Item = Result<U, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `inner`だけが構造的にピン留めされるフィールドであり、ここでのみ
+        // `Pin`越しに触れる。`transform`と`backing_store`は`Pin`を経由せず
+        // 直接借用するだけなので、ムーブを許しても安全性は損なわれない。
+        let this = unsafe { self.get_unchecked_mut() };
+
+        if let Some(token) = this.backing_store.pop_back() {
+            return Poll::Ready(Some(Ok(token)));
+        }
+
+        let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+        inner.poll_next(cx).map(|opt| opt.map(&mut this.transform))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsyncTransformed, AsyncUnRead as _};
+    use futures_util::stream::{self, StreamExt as _};
+
+    #[tokio::test]
+    async fn transforms_each_item() {
+        let s = stream::iter(['m', 'u', 's', 'i', 'c']);
+        let mut s = AsyncTransformed::<_, _, _, std::convert::Infallible, _>::new(s, Ok);
+
+        let mut out = String::new();
+        while let Some(item) = s.next().await {
+            out.push(item.unwrap());
+        }
+        assert_eq!("music", out);
+    }
+
+    #[tokio::test]
+    async fn unread_is_returned_before_inner_stream() {
+        let s = stream::iter(['u', 's']);
+        let mut s = AsyncTransformed::<_, _, _, std::convert::Infallible, _>::new(s, Ok);
+
+        s.unread('m');
+
+        let mut out = String::new();
+        while let Some(item) = s.next().await {
+            out.push(item.unwrap());
+        }
+        assert_eq!("mus", out);
+    }
+}
@@ -0,0 +1,253 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::marker::PhantomData;
+
+/// [Chunked]がトークン列の区切りを判定するためのトレイトです。
+///
+/// [Terminator]と、`FnMut(&U) -> bool`を実装する任意の述語の両方がこのトレイトを実装します。
+pub trait Boundary<U> {
+    /// `token`が区切りであれば`true`を返します。
+    fn is_boundary(&mut self, token: &U) -> bool;
+}
+
+/// 特定のトークンを区切りとする[Boundary]の実装です。
+///
+/// 区切りトークンは読み捨てられ、チャンクには含まれません。
+pub struct Terminator<U>(pub U);
+
+impl<U> Boundary<U> for Terminator<U>
+where
+    U: PartialEq,
+{
+    fn is_boundary(&mut self, token: &U) -> bool {
+        self.0 == *token
+    }
+}
+
+impl<U, P> Boundary<U> for P
+where
+    P: FnMut(&U) -> bool,
+{
+    fn is_boundary(&mut self, token: &U) -> bool {
+        self(token)
+    }
+}
+
+/// [Stream](crate::Stream)をトークンの区切り（終端トークンまたは述語）でグループ化し、
+/// `Result<Vec<U>, E>`を返すアダプタです。
+///
+/// 末尾に区切りのない不完全なチャンクが残っている場合、ストリームの終端でそれを1回だけ返します。
+/// 内側のストリームがエラーを返した場合、それまでに蓄積したチャンクを捨ててエラーをそのまま返します。
+///
+/// `unread`で押し戻されたチャンクを再び区切るため、実際に消費した区切りトークン（末尾の
+/// 不完全なチャンクでは区切りは消費されていないので[None]）をチャンクごとに`boundary_history`へ記録する。
+pub struct Chunked<S, U, E, B>
+where
+    S: Iterator<Item = Result<U, E>> + crate::UnRead<U>,
+    B: Boundary<U>,
+{
+    inner: S,
+    boundary: B,
+    exhausted: bool,
+    boundary_history: VecDeque<Option<U>>,
+    phantom: PhantomData<E>,
+}
+
+impl<S, U, E> Chunked<S, U, E, Terminator<U>>
+where
+    S: Iterator<Item = Result<U, E>> + crate::UnRead<U>,
+    U: PartialEq,
+{
+    /// `terminator`と一致したトークンを区切りとする[Chunked]を構築します。
+    pub fn by_terminator(inner: S, terminator: U) -> Self {
+        Self {
+            inner,
+            boundary: Terminator(terminator),
+            exhausted: false,
+            boundary_history: Default::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, U, E, P> Chunked<S, U, E, P>
+where
+    S: Iterator<Item = Result<U, E>> + crate::UnRead<U>,
+    P: FnMut(&U) -> bool,
+{
+    /// `predicate`が`true`を返したトークンを区切りとする[Chunked]を構築します。
+    pub fn by_predicate(inner: S, predicate: P) -> Self {
+        Self {
+            inner,
+            boundary: predicate,
+            exhausted: false,
+            boundary_history: Default::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, U, E, B> Iterator for Chunked<S, U, E, B>
+where
+    S: Iterator<Item = Result<U, E>> + crate::UnRead<U>,
+    B: Boundary<U>,
+    E: core::error::Error + Send + Sync + 'static,
+{
+    type Item = Result<Vec<U>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let mut chunk = Vec::new();
+        loop {
+            match self.inner.next() {
+                Some(Ok(token)) => {
+                    if self.boundary.is_boundary(&token) {
+                        self.boundary_history.push_back(Some(token));
+                        return Some(Ok(chunk));
+                    }
+                    chunk.push(token);
+                }
+                Some(Err(err)) => return Some(Err(err)),
+                None => {
+                    self.exhausted = true;
+                    return if chunk.is_empty() {
+                        None
+                    } else {
+                        self.boundary_history.push_back(None);
+                        Some(Ok(chunk))
+                    };
+                }
+            }
+        }
+    }
+}
+
+impl<S, U, E, B> crate::UnRead<Vec<U>> for Chunked<S, U, E, B>
+where
+    S: Iterator<Item = Result<U, E>> + crate::UnRead<U>,
+    B: Boundary<U>,
+{
+    fn unread(&mut self, chunk: Vec<U>) {
+        self.exhausted = false;
+
+        if let Some(boundary_token) = self.boundary_history.pop_back().flatten() {
+            self.inner.unread(boundary_token);
+        }
+        self.inner.unread_from_tokens(chunk.into_iter().rev());
+    }
+
+    fn unread_from_tokens(&mut self, iter: impl IntoIterator<Item = Vec<U>>) {
+        for chunk in iter {
+            self.unread(chunk);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Chunked;
+    use crate::{Transformed, UnRead as _};
+
+    #[test]
+    fn splits_on_terminator() {
+        let s = Transformed::<_, char, _, std::convert::Infallible, _>::new("apple\ngrape\nbanana\n".chars(), Result::Ok);
+        let mut s = Chunked::by_terminator(s, '\n');
+
+        assert_eq!(vec!['a', 'p', 'p', 'l', 'e'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['g', 'r', 'a', 'p', 'e'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['b', 'a', 'n', 'a', 'n', 'a'], s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn emits_trailing_partial_chunk() {
+        let s = Transformed::<_, char, _, std::convert::Infallible, _>::new("apple\ngrape".chars(), Result::Ok);
+        let mut s = Chunked::by_terminator(s, '\n');
+
+        assert_eq!(vec!['a', 'p', 'p', 'l', 'e'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['g', 'r', 'a', 'p', 'e'], s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn unread_whole_chunk_restores_order_and_boundary() {
+        let s = Transformed::<_, char, _, std::convert::Infallible, _>::new("apple\ngrape\n".chars(), Result::Ok);
+        let mut s = Chunked::by_terminator(s, '\n');
+
+        let first = s.next().unwrap().unwrap();
+        assert_eq!(vec!['a', 'p', 'p', 'l', 'e'], first);
+
+        s.unread(first);
+
+        assert_eq!(vec!['a', 'p', 'p', 'l', 'e'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['g', 'r', 'a', 'p', 'e'], s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn unread_trailing_partial_chunk_does_not_inject_a_terminator() {
+        let s = Transformed::<_, char, _, std::convert::Infallible, _>::new("apple\ngrape".chars(), Result::Ok);
+        let mut s = Chunked::by_terminator(s, '\n');
+
+        assert_eq!(vec!['a', 'p', 'p', 'l', 'e'], s.next().unwrap().unwrap());
+
+        let trailing = s.next().unwrap().unwrap();
+        assert_eq!(vec!['g', 'r', 'a', 'p', 'e'], trailing);
+        assert!(s.next().is_none());
+
+        s.unread(trailing);
+
+        // 末尾の不完全なチャンクは区切りを消費していないので、復元後も改行は挿入されない。
+        assert_eq!(vec!['g', 'r', 'a', 'p', 'e'], s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn splits_on_predicate() {
+        let s = Transformed::<_, char, _, std::convert::Infallible, _>::new("apple,grape,banana".chars(), Result::Ok);
+        let mut s = Chunked::by_predicate(s, |ch: &char| *ch == ',');
+
+        assert_eq!(vec!['a', 'p', 'p', 'l', 'e'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['g', 'r', 'a', 'p', 'e'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['b', 'a', 'n', 'a', 'n', 'a'], s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn unread_whole_chunk_with_predicate_restores_boundary() {
+        let s = Transformed::<_, char, _, std::convert::Infallible, _>::new("apple,grape,banana".chars(), Result::Ok);
+        let mut s = Chunked::by_predicate(s, |ch: &char| *ch == ',');
+
+        let first = s.next().unwrap().unwrap();
+        assert_eq!(vec!['a', 'p', 'p', 'l', 'e'], first);
+
+        s.unread(first);
+
+        // プッシュバックした後も区切りが復元され、次のチャンクと混ざらない。
+        assert_eq!(vec!['a', 'p', 'p', 'l', 'e'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['g', 'r', 'a', 'p', 'e'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['b', 'a', 'n', 'a', 'n', 'a'], s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn unread_two_chunks_restores_distinct_boundaries() {
+        let s = Transformed::<_, char, _, std::convert::Infallible, _>::new("a,bb;ccc".chars(), Result::Ok);
+        let mut s = Chunked::by_predicate(s, |ch: &char| *ch == ',' || *ch == ';');
+
+        let first = s.next().unwrap().unwrap();
+        assert_eq!(vec!['a'], first);
+        let second = s.next().unwrap().unwrap();
+        assert_eq!(vec!['b', 'b'], second);
+
+        // 直近2チャンク分の区切り（','と';'）をそれぞれ取り違えずに復元する。
+        s.unread_from_tokens([second, first]);
+
+        assert_eq!(vec!['a'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['b', 'b'], s.next().unwrap().unwrap());
+        assert_eq!(vec!['c', 'c', 'c'], s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+}
@@ -1,7 +1,8 @@
-use std::{collections::VecDeque, iter::Iterator, marker::PhantomData};
+use alloc::collections::VecDeque;
+use core::{iter::Iterator, marker::PhantomData};
 
 /// 入力イテレータ`Iterator<Item = T>`と変換関数`FnMut(T) -> Result<U, E>`を使用して`U`の値を返す出力イテレータの実装です。
-/// 型変数`T`と`E`が`?Sized`ではない事に注意してください。このイテレータが`Box<dyn std::error::Error>`を返す事は出来ません。
+/// 型変数`T`と`E`が`?Sized`ではない事に注意してください。このイテレータが`Box<dyn core::error::Error>`を返す事は出来ません。
 pub struct Transformed<I, T, U, E, F>
 where
     I: Iterator<Item = T>,
@@ -10,6 +11,7 @@ where
     iter: I,
     transform: F,
     backing_store: VecDeque<U>,
+    peeked: VecDeque<Result<U, E>>,
     phantom: PhantomData<Result<U, E>>,
 }
 
@@ -31,12 +33,14 @@ impl<I, T, U, E, F> Iterator for Transformed<I, T, U, E, F>
 where
     I: Iterator<Item = T>,
     F: FnMut(T) -> Result<U, E>,
-    E: std::error::Error + Send + Sync + 'static,
+    E: core::error::Error + Send + Sync + 'static,
 {
     type Item = Result<U, E>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.backing_store.is_empty() {
+        if let Some(peeked) = self.peeked.pop_front() {
+            Some(peeked)
+        } else if self.backing_store.is_empty() {
             self.iter.next().map(|it| (self.transform)(it))
         } else {
             self.backing_store.pop_back().map(Ok)
@@ -44,7 +48,40 @@ where
     }
 }
 
-use std::fmt;
+impl<I, T, U, E, F> crate::Peek<U, E> for Transformed<I, T, U, E, F>
+where
+    I: Iterator<Item = T>,
+    F: FnMut(T) -> Result<U, E>,
+{
+    fn peek(&mut self) -> Option<&Result<U, E>> {
+        if self.peeked.is_empty() {
+            if let Some(token) = self.backing_store.pop_back() {
+                self.peeked.push_back(Ok(token));
+            } else if let Some(item) = self.iter.next() {
+                self.peeked.push_back((self.transform)(item));
+            }
+        }
+
+        self.peeked.front()
+    }
+
+    fn peek_n(&mut self, n: usize) -> &[Result<U, E>] {
+        while self.peeked.len() < n {
+            if let Some(token) = self.backing_store.pop_back() {
+                self.peeked.push_back(Ok(token));
+            } else if let Some(item) = self.iter.next() {
+                self.peeked.push_back((self.transform)(item));
+            } else {
+                break;
+            }
+        }
+
+        let bound = n.min(self.peeked.len());
+        &self.peeked.make_contiguous()[..bound]
+    }
+}
+
+use core::fmt;
 
 impl<I, T, U, E, F> fmt::Debug for Transformed<I, T, U, E, F>
 where
@@ -52,10 +89,12 @@ where
     F: FnMut(T) -> Result<U, E>,
     T: fmt::Debug,
     U: fmt::Debug,
+    E: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("CommonStream")
             .field("backing_store", &self.backing_store)
+            .field("peeked", &self.peeked)
             .field("phantom", &self.phantom)
             .finish()
     }
@@ -71,6 +110,7 @@ where
             iter,
             transform,
             backing_store: Default::default(),
+            peeked: Default::default(),
             phantom: PhantomData,
         }
     }
@@ -80,6 +120,7 @@ where
             iter,
             transform,
             backing_store: VecDeque::from_iter(backing_store),
+            peeked: Default::default(),
             phantom: PhantomData,
         }
     }
@@ -128,7 +169,7 @@ mod tests {
                         Some(Ok(buf))
                     }
                 }
-                Err(err) => Some(Err(err.into())),
+                Err(err) => Some(Err(err)),
             }
         }
     }
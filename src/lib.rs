@@ -1,11 +1,27 @@
 //! `Iterator<Item = Result<T, E>>`型に追加の制約を与えることで任意のストリームから任意のトークンの読み出しを実現するクレート。
 //!
 //! [抽象化](self::Stream)と[エラーに関する更なる追加の制約を与えるトレイト](self::SendSyncStream)と[単純で汎用のイテレータ実装](self::Transformed)で構成される。
+//!
+//! 既定で有効な`std`フィーチャを無効化すると`#![no_std]` + `alloc`で利用できます。
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "async")]
+mod r#async;
+mod chunked;
+mod located;
 mod r#impl;
 
+#[cfg(feature = "async")]
+pub use r#async::{AsyncTransformed, AsyncUnRead};
+pub use chunked::{Boundary, Chunked, Terminator};
+pub use located::{Located, LocatedError, Span};
 pub use r#impl::Transformed;
 
-use std::iter::{IntoIterator, Iterator};
+use core::iter::{IntoIterator, Iterator};
 
 /// 恒等関数
 ///
@@ -30,7 +46,7 @@ where
 
 impl<T, TK, E> Stream<TK, E> for T
 where
-    E: std::error::Error,
+    E: core::error::Error,
     T: Iterator<Item = Result<TK, E>>,
 {
 }
@@ -44,11 +60,28 @@ where
 
 impl<T, TK, E> SendSyncStream<TK, E> for T
 where
-    E: std::error::Error + Send + Sync + 'static,
+    E: core::error::Error + Send + Sync + 'static,
     T: Iterator<Item = Result<TK, E>>,
 {
 }
 
+/// 先読み機能を追加するトレイトです。
+///
+/// `next`でトークンを消費せずに、次に読み込まれる値を参照できます。
+///
+/// 既に先読みした値は、その後に[UnRead]でプッシュバックしたトークンより先に返ります。
+pub trait Peek<U, E> {
+    /// 次に読み込まれる値を消費せずに参照します。
+    ///
+    /// ストリームが終端に達している場合は[None]を返します。
+    fn peek(&mut self) -> Option<&Result<U, E>>;
+
+    /// 次に読み込まれる値を`n`個まで消費せずに参照します。
+    ///
+    /// ストリームが途中で終端に達した場合、それまでに読み込めた分だけを返します。
+    fn peek_n(&mut self, n: usize) -> &[Result<U, E>];
+}
+
 /// プッシュバック機能を追加するトレイトです。
 ///
 /// このトレイトのメソッドから戻ると次に読み込まれる値はプッシュバックされたトークンと同じになります。
@@ -114,6 +147,71 @@ mod tests {
         assert!(s.next().is_none());
     }
 
+    #[test]
+    fn peek_does_not_consume() {
+        use super::Peek as _;
+
+        let s = [0x006d, 0x0075, 0x0073, 0x0069, 0x0063].iter().cloned();
+        let mut s = Transformed::new(decode_utf16(s), ident);
+
+        assert_eq!(Some(&Ok('m')), s.peek());
+        assert_eq!(Some(&Ok('m')), s.peek());
+        assert_eq!('m', s.next().map(Result::ok).unwrap().unwrap());
+        assert_eq!('u', s.next().map(Result::ok).unwrap().unwrap());
+    }
+
+    #[test]
+    fn peek_then_unread_preserves_order() {
+        use super::{Peek as _, UnRead as _};
+
+        let s = [0x006d, 0x0075].iter().cloned();
+        let mut s = Transformed::new(decode_utf16(s), ident);
+
+        assert_eq!(Some(&Ok('m')), s.peek());
+        s.unread('x');
+
+        // 既に`peek`した'm'はプッシュバックした'x'より先に返る。
+        assert_eq!('m', s.next().map(Result::ok).unwrap().unwrap());
+        assert_eq!('x', s.next().map(Result::ok).unwrap().unwrap());
+        assert_eq!('u', s.next().map(Result::ok).unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn peek_n_bounded_lookahead() {
+        use super::Peek as _;
+
+        let s = [0x006d, 0x0075, 0x0073].iter().cloned();
+        let mut s = Transformed::new(decode_utf16(s), ident);
+
+        let peeked = s.peek_n(2).iter().map(|r| *r.as_ref().unwrap()).collect::<Vec<_>>();
+        assert_eq!(vec!['m', 'u'], peeked);
+
+        // 要求した数を超えるストリーム終端でも、読める分だけを返す。
+        let peeked = s.peek_n(5).iter().map(|r| *r.as_ref().unwrap()).collect::<Vec<_>>();
+        assert_eq!(vec!['m', 'u', 's'], peeked);
+
+        assert_eq!('m', s.next().map(Result::ok).unwrap().unwrap());
+        assert_eq!('u', s.next().map(Result::ok).unwrap().unwrap());
+        assert_eq!('s', s.next().map(Result::ok).unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn peek_n_returns_bounded_view_even_after_a_larger_peek() {
+        use super::Peek as _;
+
+        let s = [0x006d, 0x0075, 0x0073].iter().cloned();
+        let mut s = Transformed::new(decode_utf16(s), ident);
+
+        let peeked = s.peek_n(3).iter().map(|r| *r.as_ref().unwrap()).collect::<Vec<_>>();
+        assert_eq!(vec!['m', 'u', 's'], peeked);
+
+        // 既に3件先読みしていても、`peek_n(1)`は1件だけを返す。
+        let peeked = s.peek_n(1).iter().map(|r| *r.as_ref().unwrap()).collect::<Vec<_>>();
+        assert_eq!(vec!['m'], peeked);
+    }
+
     #[test]
     fn stream_from_iter_decode_identify() {
         let s = [0x006d, 0x0075, 0x0073, 0x0069, 0x0063].iter().cloned();
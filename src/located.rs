@@ -0,0 +1,210 @@
+use alloc::collections::VecDeque;
+use core::{fmt, marker::PhantomData};
+
+/// ストリーム中の位置を表す型です。
+///
+/// `offset`は読み込んだトークンの総数、`line`と`column`は`char`ストリームの既定の
+/// 前進規則（[Located::for_chars]）が使用する行番号・桁番号です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub offset: usize,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// [Located]がエラーを返す際に、元のエラーに発生位置を付与したものです。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatedError<E> {
+    pub span: Span,
+    pub error: E,
+}
+
+impl<E> fmt::Display for LocatedError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {} (offset {})",
+            self.error, self.span.line, self.span.column, self.span.offset
+        )
+    }
+}
+
+impl<E> core::error::Error for LocatedError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// `char`ストリームのための既定の前進規則です。
+///
+/// `'\n'`を読むと行番号を進めて桁番号を0に戻し、それ以外は桁番号を進めます。`offset`は常に1増えます。
+fn advance_char(token: &char, position: &mut Span) {
+    position.offset += 1;
+    if *token == '\n' {
+        position.line += 1;
+        position.column = 0;
+    } else {
+        position.column += 1;
+    }
+}
+
+/// 任意の[Stream](crate::Stream)に読み込み位置を付与するアダプタです。
+///
+/// トークン・エラーのどちらにも[Span]が付与された`Result<(TK, Span), LocatedError<E>>`を返します。
+/// [UnRead](crate::UnRead)でトークンをプッシュバックすると、そのトークンが読み込まれた時点の[Span]も一緒に復元されます。
+pub struct Located<S, TK, E, F>
+where
+    S: Iterator<Item = Result<TK, E>>,
+    F: FnMut(&TK, &mut Span),
+{
+    inner: S,
+    advance: F,
+    position: Span,
+    backing_store: VecDeque<TK>,
+    span_store: VecDeque<Span>,
+    phantom: PhantomData<E>,
+}
+
+impl<S, TK, E, F> Located<S, TK, E, F>
+where
+    S: Iterator<Item = Result<TK, E>>,
+    F: FnMut(&TK, &mut Span),
+{
+    /// `advance`で次のトークンへの前進規則を指定して[Located]を構築します。
+    pub fn new(inner: S, advance: F) -> Self {
+        Self {
+            inner,
+            advance,
+            position: Span::default(),
+            backing_store: Default::default(),
+            span_store: Default::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// 現在の読み込み位置を返します。
+    ///
+    /// プッシュバックされたトークンが残っている場合、次に`next`が返すトークンの[Span]
+    /// （＝プッシュバックされた中で最も新しいもの）を返します。
+    pub fn position(&self) -> Span {
+        self.span_store.back().copied().unwrap_or(self.position)
+    }
+}
+
+impl<S, E> Located<S, char, E, fn(&char, &mut Span)>
+where
+    S: Iterator<Item = Result<char, E>>,
+{
+    /// `char`ストリームに対して既定の前進規則（改行で行を進め、それ以外は桁を進める）を使用する[Located]を構築します。
+    pub fn for_chars(inner: S) -> Self {
+        Self::new(inner, advance_char)
+    }
+}
+
+impl<S, TK, E, F> crate::UnRead<(TK, Span)> for Located<S, TK, E, F>
+where
+    S: Iterator<Item = Result<TK, E>>,
+    F: FnMut(&TK, &mut Span),
+{
+    fn unread(&mut self, token: (TK, Span)) {
+        let (token, span) = token;
+        self.backing_store.push_back(token);
+        self.span_store.push_back(span);
+    }
+
+    fn unread_from_tokens(&mut self, iter: impl IntoIterator<Item = (TK, Span)>) {
+        for (token, span) in iter {
+            self.backing_store.push_back(token);
+            self.span_store.push_back(span);
+        }
+    }
+}
+
+impl<S, TK, E, F> Iterator for Located<S, TK, E, F>
+where
+    S: Iterator<Item = Result<TK, E>>,
+    F: FnMut(&TK, &mut Span),
+    E: core::error::Error + Send + Sync + 'static,
+{
+    type Item = Result<(TK, Span), LocatedError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.backing_store.is_empty() {
+            let token = self.backing_store.pop_back();
+            let span = self.span_store.pop_back();
+            return match (token, span) {
+                (Some(token), Some(span)) => Some(Ok((token, span))),
+                _ => None,
+            };
+        }
+
+        match self.inner.next() {
+            Some(Ok(token)) => {
+                let span = self.position;
+                (self.advance)(&token, &mut self.position);
+                Some(Ok((token, span)))
+            }
+            Some(Err(error)) => Some(Err(LocatedError { span: self.position, error })),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Located, Span};
+    use crate::UnRead as _;
+
+    #[test]
+    fn tracks_line_and_column_for_chars() {
+        let mut s = Located::for_chars("ab\ncd".chars().map(Ok::<_, std::convert::Infallible>));
+
+        assert_eq!(('a', Span { offset: 0, line: 0, column: 0 }), s.next().unwrap().unwrap());
+        assert_eq!(('b', Span { offset: 1, line: 0, column: 1 }), s.next().unwrap().unwrap());
+        assert_eq!(('\n', Span { offset: 2, line: 0, column: 2 }), s.next().unwrap().unwrap());
+        assert_eq!(('c', Span { offset: 3, line: 1, column: 0 }), s.next().unwrap().unwrap());
+        assert_eq!(('d', Span { offset: 4, line: 1, column: 1 }), s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn unread_restores_span() {
+        let mut s = Located::for_chars("ab".chars().map(Ok::<_, std::convert::Infallible>));
+
+        let first = s.next().unwrap().unwrap();
+        assert_eq!(('a', Span { offset: 0, line: 0, column: 0 }), first);
+
+        let second = s.next().unwrap().unwrap();
+        assert_eq!(('b', Span { offset: 1, line: 0, column: 1 }), second);
+
+        s.unread(second);
+        s.unread(first);
+
+        assert_eq!(('a', Span { offset: 0, line: 0, column: 0 }), s.next().unwrap().unwrap());
+        assert_eq!(('b', Span { offset: 1, line: 0, column: 1 }), s.next().unwrap().unwrap());
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn position_reflects_pending_unread_spans() {
+        let mut s = Located::for_chars("ab".chars().map(Ok::<_, std::convert::Infallible>));
+
+        let first = s.next().unwrap().unwrap();
+        let second = s.next().unwrap().unwrap();
+        assert_eq!(Span { offset: 2, line: 0, column: 2 }, s.position());
+
+        s.unread(second);
+        // プッシュバックされた'b'の読み込み位置まで戻る。
+        assert_eq!(Span { offset: 1, line: 0, column: 1 }, s.position());
+
+        s.unread(first);
+        // さらに'a'までプッシュバックすれば、最も古い位置まで戻る。
+        assert_eq!(Span { offset: 0, line: 0, column: 0 }, s.position());
+    }
+}